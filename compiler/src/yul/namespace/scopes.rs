@@ -0,0 +1,39 @@
+use crate::yul::namespace::types::FixedSize;
+
+/// The mutability of a function, mirroring the `stateMutability` field of the
+/// Ethereum JSON ABI.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StateMutability {
+    Pure,
+    View,
+    Nonpayable,
+    Payable,
+}
+
+impl StateMutability {
+    /// Returns the `stateMutability` string used by the JSON ABI.
+    pub fn abi_name(&self) -> &'static str {
+        match self {
+            StateMutability::Pure => "pure",
+            StateMutability::View => "view",
+            StateMutability::Nonpayable => "nonpayable",
+            StateMutability::Payable => "payable",
+        }
+    }
+}
+
+/// A named definition exposed by a contract that the runtime needs to lower,
+/// keyed by name in the contract's definition map.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContractDef {
+    /// A callable function with its parameter and return types. A function may
+    /// return any number of values, ABI-encoded as a tuple.
+    Function {
+        params: Vec<FixedSize>,
+        returns: Vec<FixedSize>,
+        mutability: StateMutability,
+    },
+    /// A user-defined error with its parameter types, raised as a
+    /// selector-prefixed revert.
+    Error { params: Vec<FixedSize> },
+}
@@ -0,0 +1,195 @@
+/// A scalar type that occupies a single ABI word.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Base {
+    U8,
+    U16,
+    U24,
+    U32,
+    U40,
+    U48,
+    U56,
+    U64,
+    U72,
+    U80,
+    U88,
+    U96,
+    U104,
+    U112,
+    U120,
+    U128,
+    U136,
+    U144,
+    U152,
+    U160,
+    U168,
+    U176,
+    U184,
+    U192,
+    U200,
+    U208,
+    U216,
+    U224,
+    U232,
+    U240,
+    U248,
+    U256,
+    I8,
+    I16,
+    I24,
+    I32,
+    I40,
+    I48,
+    I56,
+    I64,
+    I72,
+    I80,
+    I88,
+    I96,
+    I104,
+    I112,
+    I120,
+    I128,
+    I136,
+    I144,
+    I152,
+    I160,
+    I168,
+    I176,
+    I184,
+    I192,
+    I200,
+    I208,
+    I216,
+    I224,
+    I232,
+    I240,
+    I248,
+    I256,
+    Address,
+    Byte,
+}
+
+impl Base {
+    /// Returns the packed byte width of the type (i.e. the number of
+    /// significant bytes, not the padded ABI slot size).
+    pub fn size(&self) -> usize {
+        use Base::*;
+
+        match self {
+            Address => 20,
+            Byte => 1,
+            U8 | I8 => 1,
+            U16 | I16 => 2,
+            U24 | I24 => 3,
+            U32 | I32 => 4,
+            U40 | I40 => 5,
+            U48 | I48 => 6,
+            U56 | I56 => 7,
+            U64 | I64 => 8,
+            U72 | I72 => 9,
+            U80 | I80 => 10,
+            U88 | I88 => 11,
+            U96 | I96 => 12,
+            U104 | I104 => 13,
+            U112 | I112 => 14,
+            U120 | I120 => 15,
+            U128 | I128 => 16,
+            U136 | I136 => 17,
+            U144 | I144 => 18,
+            U152 | I152 => 19,
+            U160 | I160 => 20,
+            U168 | I168 => 21,
+            U176 | I176 => 22,
+            U184 | I184 => 23,
+            U192 | I192 => 24,
+            U200 | I200 => 25,
+            U208 | I208 => 26,
+            U216 | I216 => 27,
+            U224 | I224 => 28,
+            U232 | I232 => 29,
+            U240 | I240 => 30,
+            U248 | I248 => 31,
+            U256 | I256 => 32,
+        }
+    }
+
+    /// Returns `true` if the type is a signed integer.
+    pub fn is_signed(&self) -> bool {
+        use Base::*;
+
+        matches!(
+            self,
+            I8 | I16
+                | I24
+                | I32
+                | I40
+                | I48
+                | I56
+                | I64
+                | I72
+                | I80
+                | I88
+                | I96
+                | I104
+                | I112
+                | I120
+                | I128
+                | I136
+                | I144
+                | I152
+                | I160
+                | I168
+                | I176
+                | I184
+                | I192
+                | I200
+                | I208
+                | I216
+                | I224
+                | I232
+                | I240
+                | I248
+                | I256
+        )
+    }
+}
+
+/// A fixed-length array of a scalar element type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Array {
+    pub dimension: usize,
+    pub inner: Base,
+}
+
+/// Any type whose size is known at compile time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FixedSize {
+    Base(Base),
+    Array(Array),
+}
+
+impl FixedSize {
+    /// Returns the number of bytes this type occupies once ABI-encoded, i.e.
+    /// its padded slot size rather than its packed width.
+    pub fn size(&self) -> usize {
+        match self {
+            FixedSize::Base(_) => 32,
+            FixedSize::Array(array) => {
+                if array.inner == Base::Byte {
+                    // `bytesN` is padded up to a whole number of words.
+                    ((array.dimension + 31) / 32) * 32
+                } else {
+                    // Every element of a static array occupies a full word.
+                    array.dimension * 32
+                }
+            }
+        }
+    }
+}
+
+/// Any type that a value can resolve to during Yul generation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Base(Base),
+    Array(Array),
+}
@@ -0,0 +1,124 @@
+use crate::errors::CompileError;
+use crate::yul::namespace::scopes::ContractDef;
+use crate::yul::runtime::abi::abi_type;
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Builds the canonical Ethereum JSON ABI array for a contract.
+///
+/// It walks the same definitions the dispatch `switch` is built from and emits
+/// one object per function so downstream tooling (binding generators, block
+/// explorers) can consume the contract.
+pub fn functions(
+    interface: &Vec<String>,
+    defs: &HashMap<String, ContractDef>,
+) -> Result<Value, CompileError> {
+    let entries = interface
+        .iter()
+        .map(|name| function(name.to_owned(), defs))
+        .collect::<Result<Vec<Value>, CompileError>>()?;
+
+    Ok(Value::Array(entries))
+}
+
+/// Renders the JSON ABI as a string suitable for writing out as a compiler
+/// artifact alongside the Yul.
+pub fn json(
+    interface: &Vec<String>,
+    defs: &HashMap<String, ContractDef>,
+) -> Result<String, CompileError> {
+    let value = functions(interface, defs)?;
+    serde_json::to_string(&value).map_err(|_| CompileError::static_str("Unable to serialize ABI"))
+}
+
+/// Builds a single JSON ABI entry from a function definition.
+fn function(name: String, defs: &HashMap<String, ContractDef>) -> Result<Value, CompileError> {
+    if let Some(def) = defs.get(&name) {
+        return match def {
+            ContractDef::Function {
+                params,
+                returns,
+                mutability,
+            } => Ok(json!({
+                "type": "function",
+                "name": name,
+                "inputs": params
+                    .iter()
+                    .map(|param| parameter(abi_type(param.to_owned())))
+                    .collect::<Vec<Value>>(),
+                "outputs": returns
+                    .iter()
+                    .map(|param| parameter(abi_type(param.to_owned())))
+                    .collect::<Vec<Value>>(),
+                "stateMutability": mutability.abi_name(),
+            })),
+            _ => Err(CompileError::static_str(
+                "Cannot create ABI entry from definition",
+            )),
+        };
+    }
+
+    Err(CompileError::static_str("No definition for name"))
+}
+
+/// Renders a single input/output parameter object. Parameters are unnamed in
+/// the compiler's internal representation, so the `name` is left empty.
+fn parameter(typ: String) -> Value {
+    json!({ "name": "", "type": typ })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::namespace::scopes::{ContractDef, StateMutability};
+    use crate::yul::namespace::types::{Base, FixedSize};
+    use crate::yul::runtime::abi::{json::json, selector_literal};
+
+    use std::collections::HashMap;
+    use tiny_keccak::{Hasher, Keccak};
+
+    fn keccak_selector(signature: &str) -> String {
+        let mut keccak = Keccak::v256();
+        let mut selector = [0u8; 4];
+        keccak.update(signature.as_bytes());
+        keccak.finalize(&mut selector);
+        format!("0x{}", hex::encode(selector))
+    }
+
+    #[test]
+    fn emits_parseable_json() {
+        let mut defs = HashMap::new();
+        defs.insert(
+            "bar".to_string(),
+            ContractDef::Function {
+                params: vec![FixedSize::Base(Base::U256)],
+                returns: vec![FixedSize::Base(Base::U256)],
+                mutability: StateMutability::View,
+            },
+        );
+        let interface = vec!["bar".to_string()];
+
+        let rendered = json(&interface, &defs).expect("Unable to build JSON ABI");
+        let parsed: serde_json::Value =
+            serde_json::from_str(&rendered).expect("Emitted ABI does not parse");
+
+        let entry = &parsed[0];
+        assert_eq!(entry["type"], "function");
+        assert_eq!(entry["name"], "bar");
+        assert_eq!(entry["inputs"][0]["type"], "uint256");
+        assert_eq!(entry["outputs"][0]["type"], "uint256");
+        assert_eq!(entry["stateMutability"], "view");
+
+        // The selector derived from the JSON signature agrees with the one the
+        // dispatch switch is built from.
+        let signature = format!(
+            "{}({})",
+            entry["name"].as_str().unwrap(),
+            entry["inputs"][0]["type"].as_str().unwrap()
+        );
+        assert_eq!(
+            selector_literal("bar".to_string(), &vec![FixedSize::Base(Base::U256)]).to_string(),
+            keccak_selector(&signature),
+        );
+    }
+}
@@ -0,0 +1,399 @@
+pub mod json;
+
+use crate::errors::CompileError;
+use crate::yul::namespace::scopes::ContractDef;
+use crate::yul::namespace::types::{Array, Base, FixedSize};
+
+use std::collections::HashMap;
+use tiny_keccak::{Hasher, Keccak};
+use yultsur::*;
+
+/// The ABI-derived artifacts the compiler emits for a contract: the runtime
+/// dispatcher (Yul) and the canonical JSON ABI.
+pub struct Abi {
+    pub dispatcher: yul::Statement,
+    pub json: String,
+}
+
+/// Builds every ABI artifact for a contract from its definitions so the driver
+/// can emit the JSON ABI alongside the Yul.
+pub fn compile(
+    interface: &Vec<String>,
+    defs: &HashMap<String, ContractDef>,
+) -> Result<Abi, CompileError> {
+    Ok(Abi {
+        dispatcher: switch(interface, defs)?,
+        json: json::json(interface, defs)?,
+    })
+}
+
+/// Builds a switch statement from the contract ABI.
+/// The switch's expression is the 4 left-most bytes in the calldata and each case is
+/// defined as the keccak value of each function's signature (without return data).
+pub fn switch(
+    interface: &Vec<String>,
+    defs: &HashMap<String, ContractDef>,
+) -> Result<yul::Statement, CompileError> {
+    let cases = interface
+        .into_iter()
+        .map(|name| case(name.to_owned(), defs))
+        .collect::<Result<Vec<yul::Case>, CompileError>>()?;
+
+    Ok(switch! {
+        switch (callval(0, 4))
+        [cases...]
+    })
+}
+
+pub fn case(name: String, defs: &HashMap<String, ContractDef>) -> Result<yul::Case, CompileError> {
+    if let Some(def) = defs.get(&name) {
+        return match def {
+            ContractDef::Function {
+                params, returns, ..
+            } => Ok(function_call_case(name, params, returns)),
+            _ => Err(CompileError::static_str(
+                "Cannot create case from definition",
+            )),
+        };
+    }
+
+    Err(CompileError::static_str("No definition for name"))
+}
+
+/// Builds a switch case from the function. It matches the selector, decodes the
+/// arguments from calldata, calls the function and ABI-encodes the returned
+/// tuple back into memory before returning it.
+///
+/// Outputs are encoded inline in the head of the ABI tuple: a scalar takes one
+/// 32-byte slot and a fixed-length array takes as many slots as its encoded
+/// size. All outputs are statically sized, so no tail section and no offsets
+/// are written; dynamic outputs such as `bytes`/`T[]` are not supported.
+/// https://solidity.readthedocs.io/en/v0.6.2/abi-spec.html#types
+pub fn function_call_case(
+    name: String,
+    params: &Vec<FixedSize>,
+    returns: &Vec<FixedSize>,
+) -> yul::Case {
+    let selector = selector_literal(name.clone(), &params);
+    let func = identifier! {(name)};
+    let param_exprs = parameter_expressions(&params);
+
+    if returns.is_empty() {
+        return case! {
+            case [selector] {
+                ([func]([param_exprs...]))
+            }
+        };
+    }
+
+    // Bind each returned value to a temporary so the encoder can address them.
+    let idents = (0..returns.len())
+        .map(|index| identifier! {(format!("return_{}", index))})
+        .collect::<Vec<yul::Identifier>>();
+    let value_exprs = idents
+        .iter()
+        .map(|ident| expression! { [ident.clone()] })
+        .collect::<Vec<yul::Expression>>();
+
+    let mut body = vec![statement! {
+        let [idents...] := [func]([param_exprs...])
+    }];
+    let (encode, total_size) = encode_tuple(returns, &value_exprs, 0);
+    body.extend(encode);
+    body.push(statement! { return(0, [total_size]) });
+
+    case! {
+        case [selector] {
+            [body...]
+        }
+    }
+}
+
+/// ABI-encodes `values` (whose types are `types`) into memory starting at
+/// byte `base` and returns the encoding statements together with an expression
+/// for the end pointer (i.e. the total number of bytes to return/revert,
+/// including `base`).
+///
+/// Every type the compiler can currently represent — scalars, fixed-length
+/// arrays and `bytesN` — is statically sized and therefore encoded inline in
+/// the head, so the tail is empty and all offsets resolve at build time. (When
+/// genuinely dynamic types such as `bytes`/`T[]` are added they will need a
+/// tail section; there is intentionally no such type to encode yet.)
+fn encode_tuple(
+    types: &[FixedSize],
+    values: &[yul::Expression],
+    base: usize,
+) -> (Vec<yul::Statement>, yul::Expression) {
+    let mut statements = vec![];
+    let mut ptr = base;
+
+    for (typ, value) in types.iter().zip(values) {
+        match typ {
+            FixedSize::Base(base) => {
+                let head = literal_expression! {(ptr)};
+                let value = clean_base(base, value.clone());
+                statements.push(statement! { mstore([head], [value]) });
+            }
+            FixedSize::Array(_) => {
+                // The value is a pointer to `size` bytes of already-encoded
+                // array data; copy it into place one (build-time-known) word
+                // at a time.
+                let mut offset = 0;
+                while offset < typ.size() {
+                    let head = literal_expression! {(ptr + offset)};
+                    let src = literal_expression! {(offset)};
+                    statements.push(statement! {
+                        mstore([head], mload(add([value.clone()], [src])))
+                    });
+                    offset += 32;
+                }
+            }
+        }
+        ptr += typ.size();
+    }
+
+    (statements, literal_expression! {(ptr)})
+}
+
+/// Computes the keccak-256 value of the input portion of the function signature and returns the
+/// first 4 bytes.
+///
+/// Example: "foo(uint256):(uint256)" => keccak256("foo(uint256)")
+pub fn selector_literal(name: String, params: &Vec<FixedSize>) -> yul::Literal {
+    let signature = format!(
+        "{}({})",
+        name,
+        params
+            .iter()
+            .map(|param| abi_type(param.to_owned()))
+            .collect::<Vec<String>>()
+            .join(",")
+    );
+
+    let mut keccak = Keccak::v256();
+    let mut selector = [0u8; 4];
+
+    keccak.update(signature.as_bytes());
+    keccak.finalize(&mut selector);
+
+    literal! {(format!("0x{}", hex::encode(selector)))}
+}
+
+/// Computes the 4-byte selector of a custom error, exactly as
+/// [`selector_literal`] does for functions: the first 4 bytes of the keccak-256
+/// of `Name(type,type,...)`.
+///
+/// Example: `MyError(uint256,address)` => keccak256("MyError(uint256,address)")
+pub fn error_selector(name: String, params: &Vec<FixedSize>) -> yul::Literal {
+    selector_literal(name, params)
+}
+
+/// Lowers a `raise` of a user-defined error to its revert statements.
+///
+/// This is the entry point the statement traversal calls when it encounters a
+/// `raise MyError(..)`: it looks the error up in the contract definitions and
+/// builds the selector-prefixed revert for it.
+pub fn error_revert(
+    name: String,
+    defs: &HashMap<String, ContractDef>,
+    values: &[yul::Expression],
+) -> Result<Vec<yul::Statement>, CompileError> {
+    if let Some(ContractDef::Error { params }) = defs.get(&name) {
+        return Ok(revert_with_error(name, params, values));
+    }
+
+    Err(CompileError::static_str("No error definition for name"))
+}
+
+/// Builds the statements for a selector-prefixed revert of a custom error.
+///
+/// The 4-byte error selector is written to the leftmost bytes of memory offset
+/// 0 and the error arguments are ABI-encoded inline immediately after it
+/// (starting at byte 4), then `revert(0, total_size)` is emitted so existing
+/// clients can decode the payload like any Solidity custom error.
+pub fn revert_with_error(
+    name: String,
+    params: &Vec<FixedSize>,
+    values: &[yul::Expression],
+) -> Vec<yul::Statement> {
+    let selector = error_selector(name, params);
+    let mut statements = vec![statement! {
+        mstore(0, shl(224, [selector]))
+    }];
+
+    let (encode, total_size) = encode_tuple(params, values, 4);
+    statements.extend(encode);
+    statements.push(statement! { revert(0, [total_size]) });
+
+    statements
+}
+
+pub fn abi_type_base(typ: Base) -> String {
+    match typ {
+        Base::Address => "address".to_string(),
+        Base::Byte => "byte".to_string(),
+        // Every other base is a sized integer; its Solidity name is derived
+        // from its signedness and packed bit width.
+        integer => {
+            let prefix = if integer.is_signed() { "int" } else { "uint" };
+            format!("{}{}", prefix, integer.size() * 8)
+        }
+    }
+}
+
+/// Cleans a base value loaded into a full 32-byte word so only its significant
+/// bytes are set: unsigned integers are zero-extended (masked to their width),
+/// signed integers are sign-extended from their high bit.
+fn clean_base(base: &Base, value: yul::Expression) -> yul::Expression {
+    match base {
+        Base::Address | Base::Byte => value,
+        _ if base.size() == 32 => value,
+        _ => {
+            let size = literal_expression! {(base.size())};
+
+            if base.is_signed() {
+                expression! { signextend(sub([size], 1), [value]) }
+            } else {
+                // mask = (1 << (8 * size)) - 1
+                expression! { and([value], sub(shl(mul(8, [size]), 1), 1)) }
+            }
+        }
+    }
+}
+
+pub fn abi_type(typ: FixedSize) -> String {
+    match typ {
+        FixedSize::Base(base) => abi_type_base(base),
+        FixedSize::Array(Array { dimension, inner }) => {
+            if inner == Base::Byte {
+                return format!("bytes{}", dimension);
+            }
+
+            format!("{}[{}]", abi_type_base(inner), dimension)
+        }
+    }
+}
+
+/// Creates a Vec of Yul expressions that decode each parameter from calldata.
+///
+/// All currently representable types are statically sized, so arguments are
+/// laid out back-to-back starting at byte 4 and the read pointer advances by
+/// each parameter's ABI-encoded size. A scalar is read from its right-aligned
+/// word and masked/sign-extended to its width; an array is copied inline into
+/// memory.
+pub fn parameter_expressions(params: &Vec<FixedSize>) -> Vec<yul::Expression> {
+    let mut ptr = 4;
+    let mut expressions = vec![];
+
+    for param in params.iter() {
+        let start = literal_expression! {(ptr)};
+        let size = literal_expression! {(param.size())};
+        ptr += param.size();
+
+        expressions.push(match param {
+            FixedSize::Base(base) => clean_base(base, expression! { callval([start], 32) }),
+            FixedSize::Array(_) => expression! { calltomem([start], [size]) },
+        });
+    }
+
+    expressions
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::namespace::types::{Array, Base, FixedSize};
+    use crate::yul::runtime::abi::{
+        abi_type_base, function_call_case, parameter_expressions, revert_with_error,
+        selector_literal,
+    };
+    use yultsur::*;
+
+    #[test]
+    fn test_selector_literal() {
+        assert_eq!(
+            selector_literal("bar".to_string(), &vec![FixedSize::Base(Base::U256)]).to_string(),
+            String::from("0x0423a132"),
+        )
+    }
+
+    #[test]
+    fn two_static_return_tuple() {
+        let case = function_call_case(
+            "foo".to_string(),
+            &vec![],
+            &vec![FixedSize::Base(Base::U256), FixedSize::Base(Base::U256)],
+        );
+        let yul = case.to_string();
+
+        // The two words sit inline in the head and 64 bytes are returned.
+        assert!(yul.contains("mstore(0,"), "{}", yul);
+        assert!(yul.contains("mstore(32,"), "{}", yul);
+        assert!(yul.contains("return(0, 64)"), "{}", yul);
+    }
+
+    #[test]
+    fn base_and_array_return() {
+        let case = function_call_case(
+            "foo".to_string(),
+            &vec![],
+            &vec![
+                FixedSize::Base(Base::U256),
+                FixedSize::Array(Array {
+                    inner: Base::U256,
+                    dimension: 2,
+                }),
+            ],
+        );
+        let yul = case.to_string();
+
+        // Base in the first slot, array copied word-by-word into the next two
+        // slots, 96 bytes returned in total.
+        assert!(yul.contains("mstore(0,"), "{}", yul);
+        assert!(yul.contains("mload(add("), "{}", yul);
+        assert!(yul.contains("return(0, 96)"), "{}", yul);
+    }
+
+    #[test]
+    fn revert_with_error_payload() {
+        let params = vec![FixedSize::Base(Base::U256)];
+        let statements = revert_with_error("MyError".to_string(), &params, &[expression! { x }]);
+        let yul = statements
+            .iter()
+            .map(|statement| statement.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        // Selector agrees with keccak of `MyError(uint256)`.
+        let selector = selector_literal("MyError".to_string(), &params).to_string();
+        assert!(
+            yul.contains(&format!("mstore(0, shl(224, {}))", selector)),
+            "{}",
+            yul
+        );
+        // Single static argument encoded right after the selector, then revert.
+        assert!(yul.contains("mstore(4,"), "{}", yul);
+        assert!(yul.contains("revert(0, 36)"), "{}", yul);
+    }
+
+    #[test]
+    fn abi_type_base_renders_sized_integers() {
+        assert_eq!(abi_type_base(Base::U8), "uint8");
+        assert_eq!(abi_type_base(Base::I128), "int128");
+        assert_eq!(abi_type_base(Base::U256), "uint256");
+    }
+
+    #[test]
+    fn narrow_parameters_are_masked_and_sign_extended() {
+        // Unsigned narrow integers are zero-extended by masking.
+        let unsigned = parameter_expressions(&vec![FixedSize::Base(Base::U8)]);
+        assert!(unsigned[0].to_string().contains("and("), "{}", unsigned[0]);
+
+        // Signed narrow integers are sign-extended.
+        let signed = parameter_expressions(&vec![FixedSize::Base(Base::I128)]);
+        assert!(
+            signed[0].to_string().contains("signextend("),
+            "{}",
+            signed[0]
+        );
+    }
+}
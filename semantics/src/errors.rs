@@ -0,0 +1,12 @@
+/// Errors raised while analyzing the semantics of a Fe module.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SemanticError {
+    /// An expression was used as an assignment target that cannot be assigned
+    /// to (e.g. a literal or a call).
+    UnassignableExpression,
+    /// The type of a value did not match the type it was being assigned to.
+    TypeMismatch { expected: String, found: String },
+    /// A constant subscript index fell outside the bounds of a fixed-size
+    /// array.
+    IndexOutOfRange { index: usize, size: usize },
+}
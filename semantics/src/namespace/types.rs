@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// A scalar type that occupies a single word.
+///
+/// Follow-up: the compiler's yul-side `Base` (`yul::namespace::types`) now carries
+/// the full `U8..U256`/`I8..I256` lattice for ABI generation. This semantic
+/// `Base` still only models `U256`/`Address`/`Byte`, so narrow integers cannot
+/// yet originate from Fe source; widening this lattice to match is tracked
+/// separately.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Base {
+    U256,
+    Address,
+    Byte,
+}
+
+/// A fixed-length array of a scalar element type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Array {
+    pub dimension: usize,
+    pub inner: Base,
+}
+
+/// A mapping from a key type to a value type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Map {
+    pub key: FixedSize,
+    pub value: FixedSize,
+}
+
+/// A fixed-arity sequence of element types, used for multi-value expressions
+/// such as the right-hand side of a tuple assignment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuple {
+    pub items: Vec<FixedSize>,
+}
+
+/// Any type whose size is known at compile time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FixedSize {
+    Base(Base),
+    Array(Array),
+    Tuple(Tuple),
+}
+
+/// Any type that a Fe expression can resolve to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Base(Base),
+    Array(Array),
+    Map(Map),
+    Tuple(Tuple),
+}
+
+impl Type {
+    /// Returns `true` if a value of this type can be assigned to a target of
+    /// type `other`.
+    pub fn assignable_to(&self, other: &Type) -> bool {
+        self == other
+    }
+}
+
+impl From<FixedSize> for Type {
+    fn from(fixed: FixedSize) -> Self {
+        match fixed {
+            FixedSize::Base(base) => Type::Base(base),
+            FixedSize::Array(array) => Type::Array(array),
+            FixedSize::Tuple(tuple) => Type::Tuple(tuple),
+        }
+    }
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base::U256 => write!(f, "u256"),
+            Base::Address => write!(f, "address"),
+            Base::Byte => write!(f, "byte"),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Base(base) => write!(f, "{}", base),
+            Type::Array(array) => write!(f, "{}[{}]", array.inner, array.dimension),
+            Type::Map(map) => write!(f, "map<{}, {}>", type_of(&map.key), type_of(&map.value)),
+            Type::Tuple(tuple) => {
+                let items = tuple
+                    .items
+                    .iter()
+                    .map(|item| type_of(item).to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "({})", items)
+            }
+        }
+    }
+}
+
+fn type_of(fixed: &FixedSize) -> Type {
+    Type::from(fixed.clone())
+}
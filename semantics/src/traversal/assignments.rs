@@ -3,6 +3,10 @@ use crate::namespace::scopes::{
     FunctionScope,
     Shared,
 };
+use crate::namespace::types::{
+    FixedSize,
+    Type,
+};
 use crate::traversal::expressions;
 use crate::Context;
 use fe_parser::ast as fe;
@@ -19,7 +23,7 @@ pub fn assign(
 ) -> Result<(), SemanticError> {
     if let fe::FuncStmt::Assign { targets, value } = &stmt.node {
         if targets.len() > 1 {
-            unimplemented!()
+            return assign_tuple(scope, context, targets, value);
         }
 
         if let Some(target) = targets.first() {
@@ -38,6 +42,70 @@ pub fn assign(
     unreachable!()
 }
 
+/// Gather context information for multi-target (tuple) assignments and check
+/// for type errors.
+///
+/// e.g. `a, b = foo()`, `x, y = y, x`
+fn assign_tuple(
+    scope: Shared<FunctionScope>,
+    context: Shared<Context>,
+    targets: &[Spanned<fe::Expr>],
+    value: &Spanned<fe::Expr>,
+) -> Result<(), SemanticError> {
+    let value_attributes = expressions::expr(Rc::clone(&scope), Rc::clone(&context), value)?;
+
+    let elements = match &value_attributes.typ {
+        Type::Tuple(tuple) => tuple.items.clone(),
+        _ => return Err(SemanticError::TypeMismatch {
+            expected: "tuple".to_string(),
+            found: value_attributes.typ.to_string(),
+        }),
+    };
+
+    if elements.len() != targets.len() {
+        return Err(SemanticError::TypeMismatch {
+            expected: format!("{}-element tuple", targets.len()),
+            found: value_attributes.typ.to_string(),
+        });
+    }
+
+    for (target, element) in targets.iter().zip(elements.into_iter()) {
+        assign_element(Rc::clone(&scope), Rc::clone(&context), target, element)?;
+    }
+
+    Ok(())
+}
+
+/// Checks a single tuple-unpacking target against the type of its
+/// corresponding tuple element and records the target's expression
+/// attributes.
+fn assign_element(
+    scope: Shared<FunctionScope>,
+    context: Shared<Context>,
+    target: &Spanned<fe::Expr>,
+    element: FixedSize,
+) -> Result<(), SemanticError> {
+    let target_type = match &target.node {
+        fe::Expr::Name(_) => {
+            expressions::expr(scope, context, target)?.typ
+        }
+        fe::Expr::Subscript {
+            value: container,
+            slices,
+        } => subscript_element_type(scope, context, container, slices)?.into(),
+        _ => return Err(SemanticError::UnassignableExpression),
+    };
+
+    if !Type::from(element.clone()).assignable_to(&target_type) {
+        return Err(SemanticError::TypeMismatch {
+            expected: target_type.to_string(),
+            found: Type::from(element).to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 /// Gather context information for subscript assignments and check for type
 /// errors.
 ///
@@ -53,11 +121,16 @@ fn assign_subscript(
         slices,
     } = &target.node
     {
-        let _target_attributes = expressions::expr(Rc::clone(&scope), Rc::clone(&context), target)?;
-        let _value_attributes = expressions::expr(Rc::clone(&scope), Rc::clone(&context), value)?;
-        let _index_attributes = expressions::slices_index(scope, context, slices)?;
+        let element_type =
+            subscript_element_type(Rc::clone(&scope), Rc::clone(&context), target, slices)?;
+        let value_attributes = expressions::expr(scope, context, value)?;
 
-        // TODO: perform type checking
+        if !value_attributes.typ.assignable_to(&element_type.clone().into()) {
+            return Err(SemanticError::TypeMismatch {
+                expected: Type::from(element_type).to_string(),
+                found: value_attributes.typ.to_string(),
+            });
+        }
 
         return Ok(());
     }
@@ -65,6 +138,53 @@ fn assign_subscript(
     unreachable!()
 }
 
+/// Resolves the element type of an indexed container and checks the index
+/// expression, shared by single- and multi-target subscript assignments.
+///
+/// For an `Array { inner, dimension }` the element type is `inner` and the
+/// index must be the implicit `u256`; a constant literal index `>= dimension`
+/// is rejected as out of range. For a `Map { key, value }` the element type is
+/// `value` and the index must match `key`.
+fn subscript_element_type(
+    scope: Shared<FunctionScope>,
+    context: Shared<Context>,
+    container: &Spanned<fe::Expr>,
+    slices: &Spanned<Vec<Spanned<fe::Slice>>>,
+) -> Result<FixedSize, SemanticError> {
+    let container_attributes =
+        expressions::expr(Rc::clone(&scope), Rc::clone(&context), container)?;
+    let index_attributes = expressions::slices_index(scope, context, slices)?;
+
+    let (element_type, index_type) = match &container_attributes.typ {
+        Type::Array(array) => {
+            if let Some(index) = const_index(slices) {
+                if index >= array.dimension {
+                    return Err(SemanticError::IndexOutOfRange {
+                        index,
+                        size: array.dimension,
+                    });
+                }
+            }
+
+            (
+                FixedSize::Base(array.inner.clone()),
+                Type::Base(crate::namespace::types::Base::U256),
+            )
+        }
+        Type::Map(map) => (map.value.clone(), map.key.clone().into()),
+        _ => return Err(SemanticError::UnassignableExpression),
+    };
+
+    if index_attributes.typ != index_type {
+        return Err(SemanticError::TypeMismatch {
+            expected: index_type.to_string(),
+            found: index_attributes.typ.to_string(),
+        });
+    }
+
+    Ok(element_type)
+}
+
 /// Gather context information for named assignments and check for type errors.
 ///
 /// e.g. `foo = 42`
@@ -74,14 +194,33 @@ fn assign_name(
     target: &Spanned<fe::Expr>,
     value: &Spanned<fe::Expr>,
 ) -> Result<(), SemanticError> {
-    let _target_attributes = expressions::expr(Rc::clone(&scope), Rc::clone(&context), target)?;
-    let _value_attributes = expressions::expr(scope, context, value)?;
+    let target_attributes = expressions::expr(Rc::clone(&scope), Rc::clone(&context), target)?;
+    let value_attributes = expressions::expr(scope, context, value)?;
 
-    // TODO:: Perform type checking
+    if !value_attributes.typ.assignable_to(&target_attributes.typ) {
+        return Err(SemanticError::TypeMismatch {
+            expected: target_attributes.typ.to_string(),
+            found: value_attributes.typ.to_string(),
+        });
+    }
 
     Ok(())
 }
 
+/// Returns the value of a subscript index when it is a constant integer
+/// literal, otherwise `None`.
+fn const_index(slices: &Spanned<Vec<Spanned<fe::Slice>>>) -> Option<usize> {
+    if let Some(slice) = slices.node.first() {
+        if let fe::Slice::Index(index) = &slice.node {
+            if let fe::Expr::Num(num) = &index.node {
+                return num.parse::<usize>().ok();
+            }
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use crate::namespace::scopes::{
@@ -96,6 +235,7 @@ mod tests {
         FixedSize,
         Map,
     };
+    use crate::errors::SemanticError;
     use crate::traversal::assignments::assign;
     use crate::Context;
     use fe_parser as parser;
@@ -116,6 +256,9 @@ mod tests {
         function_scope
             .borrow_mut()
             .add_base("foo".to_string(), Base::U256);
+        function_scope
+            .borrow_mut()
+            .add_base("baz".to_string(), Base::U256);
         function_scope.borrow_mut().add_array(
             "bar".to_string(),
             Array {
@@ -126,6 +269,16 @@ mod tests {
         function_scope
     }
 
+    fn try_assign(scope: Shared<FunctionScope>, src: &str) -> Result<(), SemanticError> {
+        let context = Context::new_shared();
+        let tokens = parser::get_parse_tokens(src).expect("Couldn't parse expression");
+        let assignment = &parser::parsers::assign_stmt(&tokens[..])
+            .expect("Couldn't build assigment AST")
+            .1;
+
+        assign(scope, context, assignment)
+    }
+
     fn analyze(scope: Shared<FunctionScope>, src: &str) -> Context {
         let context = Context::new_shared();
         let tokens = parser::get_parse_tokens(src).expect("Couldn't parse expression");
@@ -151,4 +304,30 @@ mod tests {
         let context = analyze(scope(), assignment);
         assert_eq!(context.expressions.len(), expected_num_expr_attrs)
     }
+
+    #[rstest(
+        assignment,
+        case("bar[100] = 42"),
+        case("bar[200] = 42")
+    )]
+    fn index_out_of_range(assignment: &str) {
+        assert!(try_assign(scope(), assignment).is_err());
+    }
+
+    #[test]
+    fn swap_type_checks() {
+        // `x, y = y, x` unpacks a matching two-element tuple.
+        assert!(try_assign(scope(), "foo, baz = baz, foo").is_ok());
+    }
+
+    #[rstest(
+        assignment,
+        // right-hand side is not a two-element tuple
+        case("foo, baz = foo"),
+        // subscript target is indexed out of range
+        case("foo, bar[200] = baz, foo")
+    )]
+    fn rejects_bad_tuple_assignment(assignment: &str) {
+        assert!(try_assign(scope(), assignment).is_err());
+    }
 }